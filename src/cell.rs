@@ -0,0 +1,47 @@
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Cell {
+    contents: String,
+    attrs: crate::attrs::Attrs,
+    // the blank left in the last column by Grid::col_wrap when a wide
+    // glyph got pushed to the start of the next row - contributes nothing
+    // to contents() et al, so the wide glyph's rendered text isn't
+    // preceded by a stray blank.
+    wide_spacer: bool,
+}
+
+impl Cell {
+    pub fn contents(&self) -> &str {
+        &self.contents
+    }
+
+    pub fn has_contents(&self) -> bool {
+        !self.contents.is_empty()
+    }
+
+    pub fn attrs(&self) -> &crate::attrs::Attrs {
+        &self.attrs
+    }
+
+    pub fn set(&mut self, contents: String, attrs: crate::attrs::Attrs) {
+        self.contents = contents;
+        self.attrs = attrs;
+        self.wide_spacer = false;
+    }
+
+    pub fn clear(&mut self, bgcolor: crate::attrs::Color) {
+        self.contents.clear();
+        self.attrs = crate::attrs::Attrs {
+            bgcolor,
+            ..crate::attrs::Attrs::default()
+        };
+        self.wide_spacer = false;
+    }
+
+    pub fn is_wide_spacer(&self) -> bool {
+        self.wide_spacer
+    }
+
+    pub fn set_is_wide_spacer(&mut self, wide_spacer: bool) {
+        self.wide_spacer = wide_spacer;
+    }
+}
@@ -6,6 +6,9 @@ pub struct Grid {
     pos: Pos,
     saved_pos: Pos,
     rows: Vec<crate::row::Row>,
+    scrollback: std::collections::VecDeque<crate::row::Row>,
+    scrollback_len: usize,
+    scrollback_offset: usize,
     scroll_top: u16,
     scroll_bottom: u16,
     origin_mode: bool,
@@ -13,12 +16,15 @@ pub struct Grid {
 }
 
 impl Grid {
-    pub fn new(size: Size) -> Self {
+    pub fn new(size: Size, scrollback_len: usize) -> Self {
         Self {
             size,
             pos: Pos::default(),
             saved_pos: Pos::default(),
             rows: vec![crate::row::Row::new(size.cols); size.rows as usize],
+            scrollback: std::collections::VecDeque::new(),
+            scrollback_len,
+            scrollback_offset: 0,
             scroll_top: 0,
             scroll_bottom: size.rows - 1,
             origin_mode: false,
@@ -92,6 +98,22 @@ impl Grid {
     }
 
     pub fn rows(&self) -> impl Iterator<Item = &crate::row::Row> {
+        let offset = self.scrollback_offset;
+        let rows = self.size.rows as usize;
+        let from_scrollback = offset.min(rows);
+        let from_live = rows.saturating_sub(offset);
+        let scrollback_skip = self.scrollback.len() - offset;
+        self.scrollback
+            .iter()
+            .skip(scrollback_skip)
+            .take(from_scrollback)
+            .chain(self.rows.iter().take(from_live))
+    }
+
+    // unaffected by scrollback_offset - used by anything that needs to see
+    // the live screen contents regardless of where the viewport is
+    // currently scrolled back to.
+    fn rows_raw(&self) -> impl Iterator<Item = &crate::row::Row> {
         self.rows.iter()
     }
 
@@ -99,6 +121,14 @@ impl Grid {
         self.rows.iter_mut()
     }
 
+    pub fn scrollback(&self) -> usize {
+        self.scrollback_offset
+    }
+
+    pub fn set_scrollback(&mut self, rows: usize) {
+        self.scrollback_offset = rows.min(self.scrollback.len());
+    }
+
     pub fn row(&self, pos: Pos) -> Option<&crate::row::Row> {
         self.rows.get(pos.row as usize)
     }
@@ -136,11 +166,83 @@ impl Grid {
         contents.trim_end().to_string()
     }
 
+    // start and end are absolute coordinates into the concatenation of
+    // scrollback followed by the live rows, with row 0 being the oldest
+    // scrollback line - not relative to the current scrollback_offset.
+    pub fn get_selected_text(&self, start: Pos, end: Pos) -> String {
+        let (start, end) = if (start.row, start.col) <= (end.row, end.col) {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let mut contents = String::new();
+        let rows: Vec<&crate::row::Row> =
+            self.scrollback.iter().chain(self.rows.iter()).collect();
+        for (i, row) in rows
+            .iter()
+            .enumerate()
+            .take(end.row as usize + 1)
+            .skip(start.row as usize)
+        {
+            let col_lo = if i as u16 == start.row { start.col } else { 0 };
+            let col_hi = if i as u16 == end.row {
+                end.col
+            } else {
+                self.size.cols
+            };
+            contents += &row.contents(col_lo, col_hi);
+            if i as u16 != end.row && !row.wrapped() {
+                contents += "\n";
+            }
+        }
+        contents
+    }
+
+    // reconstructs logical lines by joining rows across soft-wrap
+    // boundaries before matching, so a url that wraps mid-match is still
+    // found, then maps the match's char offsets back onto row/col pairs.
+    // scans rows_raw rather than rows so the returned Pos values always
+    // index directly into the live grid, matching row()/cell().
+    pub fn urls(&self) -> Vec<(Pos, Pos, String)> {
+        let mut urls = Vec::new();
+        let rows: Vec<&crate::row::Row> = self.rows_raw().collect();
+        let mut row_idx = 0;
+        while row_idx < rows.len() {
+            let mut line = String::new();
+            let mut offsets = Vec::new();
+            let mut last_idx = row_idx;
+            loop {
+                let row = rows[last_idx];
+                for (col, ch) in
+                    row.contents(0, self.size.cols).chars().enumerate()
+                {
+                    line.push(ch);
+                    offsets.push(Pos {
+                        row: last_idx as u16,
+                        col: col as u16,
+                    });
+                }
+                if row.wrapped() && last_idx + 1 < rows.len() {
+                    last_idx += 1;
+                } else {
+                    break;
+                }
+            }
+
+            for (start, end, text) in find_urls(&line) {
+                urls.push((offsets[start], offsets[end - 1], text));
+            }
+
+            row_idx = last_idx + 1;
+        }
+        urls
+    }
+
     pub fn contents_formatted(&self) -> Vec<u8> {
         let mut contents = b"\x1b[H\x1b[J".to_vec();
         let mut prev_attrs = crate::attrs::Attrs::default();
         let mut final_col = 0;
-        for row in self.rows() {
+        for row in self.rows_raw() {
             let (mut new_contents, new_attrs, new_col) =
                 row.contents_formatted(0, self.size.cols, prev_attrs);
             if !new_contents.is_empty() {
@@ -174,7 +276,8 @@ impl Grid {
         let mut prev_attrs = crate::attrs::Attrs::default();
         let mut final_row = prev.pos.row;
         let mut final_col = prev.pos.col;
-        for (idx, (row, prev_row)) in self.rows().zip(prev.rows()).enumerate()
+        for (idx, (row, prev_row)) in
+            self.rows_raw().zip(prev.rows_raw()).enumerate()
         {
             let (mut new_contents, new_attrs, new_col) =
                 row.contents_diff(prev_row, 0, self.size.cols, prev_attrs);
@@ -265,8 +368,7 @@ impl Grid {
     pub fn erase_cells(&mut self, count: u16, bgcolor: crate::attrs::Color) {
         let pos = self.pos;
         let row = self.current_row_mut();
-        for cell in
-            row.cells_mut().skip(pos.col as usize).take(count as usize)
+        for cell in row.cells_mut().skip(pos.col as usize).take(count as usize)
         {
             cell.clear(bgcolor);
         }
@@ -288,10 +390,18 @@ impl Grid {
     }
 
     pub fn scroll_up(&mut self, count: u16) {
+        let full_screen =
+            self.scroll_top == 0 && self.scroll_bottom == self.size.rows - 1;
         for _ in 0..(count.min(self.size.rows - self.scroll_top)) {
             self.rows
                 .insert(self.scroll_bottom as usize + 1, self.new_row());
-            self.rows.remove(self.scroll_top as usize);
+            let evicted = self.rows.remove(self.scroll_top as usize);
+            if full_screen {
+                if self.scrollback.len() >= self.scrollback_len {
+                    self.scrollback.pop_front();
+                }
+                self.scrollback.push_back(evicted);
+            }
         }
     }
 
@@ -339,11 +449,7 @@ impl Grid {
     pub fn row_dec_scroll(&mut self, count: u16) {
         // need to account for clamping by both row_clamp_top and by
         // saturating_sub
-        let extra_lines = if count > self.pos.row {
-            count - self.pos.row
-        } else {
-            0
-        };
+        let extra_lines = count.saturating_sub(self.pos.row);
         self.pos.row = self.pos.row.saturating_sub(count);
         let lines = self.row_clamp_top(true);
         self.scroll_down(lines + extra_lines);
@@ -381,6 +487,13 @@ impl Grid {
 
     pub fn col_wrap(&mut self, width: u16) {
         if self.pos.col > self.size.cols - width {
+            // a wide glyph that doesn't fit in the last column can't be
+            // split across the wrap boundary - leave a blank spacer behind
+            // instead and write the glyph at the start of the next row.
+            if width > 1 && self.pos.col == self.size.cols - 1 {
+                self.current_cell_mut().clear(crate::attrs::Color::Default);
+                self.current_cell_mut().set_is_wide_spacer(true);
+            }
             self.current_row_mut().wrap(true);
             self.pos.col = 0;
             self.row_inc_scroll(1);
@@ -430,3 +543,161 @@ pub struct Pos {
     pub row: u16,
     pub col: u16,
 }
+
+const URL_SCHEMES: &[&str] =
+    &["http://", "https://", "ftp://", "mailto:", "file://"];
+
+// finds non-overlapping url-like substrings in a single logical line,
+// returning (start char offset, end char offset, matched text) triples.
+fn find_urls(line: &str) -> Vec<(usize, usize, String)> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let at_boundary = i == 0 || !chars[i - 1].is_alphanumeric();
+        let scheme = if at_boundary {
+            URL_SCHEMES.iter().find(|scheme| {
+                chars[i..]
+                    .iter()
+                    .copied()
+                    .take(scheme.chars().count())
+                    .eq(scheme.chars())
+            })
+        } else {
+            None
+        };
+
+        let Some(scheme) = scheme else {
+            i += 1;
+            continue;
+        };
+
+        let scheme_len = scheme.chars().count();
+        let mut end = i + scheme_len;
+        while end < chars.len() && !chars[end].is_whitespace() {
+            end += 1;
+        }
+        while end > i + scheme_len
+            && matches!(
+                chars[end - 1],
+                '.' | ',' | ';' | ':' | ')' | ']' | '>'
+            )
+        {
+            end -= 1;
+        }
+
+        if end > i + scheme_len {
+            matches.push((i, end, chars[i..end].iter().collect()));
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_row(grid: &mut Grid, row: u16, text: &str, wrapped: bool) {
+        let r = grid.row_mut(Pos { row, col: 0 }).unwrap();
+        for (col, ch) in text.chars().enumerate() {
+            r.get_mut(col as u16)
+                .unwrap()
+                .set(ch.to_string(), crate::attrs::Attrs::default());
+        }
+        r.wrap(wrapped);
+    }
+
+    #[test]
+    fn scrollback_windowing_at_the_live_boundary() {
+        let size = Size { rows: 3, cols: 4 };
+        let mut grid = Grid::new(size, 10);
+        for n in 0..6u8 {
+            grid.current_row_mut().get_mut(0).unwrap().set(
+                ((b'a' + n) as char).to_string(),
+                crate::attrs::Attrs::default(),
+            );
+            grid.scroll_up(1);
+        }
+
+        assert_eq!(grid.scrollback(), 0);
+        let live: Vec<String> =
+            grid.rows().map(|r| r.contents(0, 1)).collect();
+        assert_eq!(live, vec![" ", " ", " "]);
+
+        grid.set_scrollback(2);
+        assert_eq!(grid.scrollback(), 2);
+        let windowed: Vec<String> =
+            grid.rows().map(|r| r.contents(0, 1)).collect();
+        assert_eq!(windowed, vec!["e", "f", " "]);
+
+        // clamps to the scrollback length, not just the screen height.
+        grid.set_scrollback(100);
+        assert_eq!(grid.scrollback(), 6);
+    }
+
+    #[test]
+    fn get_selected_text_normalizes_a_reversed_selection() {
+        let size = Size { rows: 2, cols: 5 };
+        let mut grid = Grid::new(size, 0);
+        write_row(&mut grid, 0, "hello", true);
+        write_row(&mut grid, 1, "world", false);
+
+        let forward = grid
+            .get_selected_text(Pos { row: 0, col: 0 }, Pos { row: 1, col: 5 });
+        assert_eq!(forward, "helloworld");
+
+        let reversed = grid
+            .get_selected_text(Pos { row: 1, col: 5 }, Pos { row: 0, col: 0 });
+        assert_eq!(reversed, forward);
+
+        let same_row_reversed = grid
+            .get_selected_text(Pos { row: 1, col: 5 }, Pos { row: 1, col: 0 });
+        assert_eq!(same_row_reversed, "world");
+    }
+
+    #[test]
+    fn col_wrap_leaves_a_spacer_for_a_wide_glyph_in_the_last_column() {
+        let size = Size { rows: 2, cols: 4 };
+        let mut grid = Grid::new(size, 0);
+        grid.set_pos(Pos { row: 0, col: 3 });
+        grid.current_cell_mut()
+            .set("X".to_string(), crate::attrs::Attrs::default());
+
+        grid.col_wrap(2);
+
+        let spacer = grid.cell(Pos { row: 0, col: 3 }).unwrap();
+        assert!(spacer.is_wide_spacer());
+        assert!(!spacer.has_contents());
+        assert!(grid.row(Pos { row: 0, col: 0 }).unwrap().wrapped());
+        assert_eq!(grid.pos(), Pos { row: 1, col: 0 });
+    }
+
+    #[test]
+    fn find_urls_requires_a_non_alphanumeric_boundary() {
+        let found = find_urls("(https://example.com) and xhttp://bad");
+        let texts: Vec<&str> =
+            found.iter().map(|(_, _, s)| s.as_str()).collect();
+        assert_eq!(texts, vec!["https://example.com"]);
+    }
+
+    #[test]
+    fn urls_detects_a_url_that_wraps_across_rows() {
+        let size = Size { rows: 2, cols: 6 };
+        let mut grid = Grid::new(size, 0);
+        write_row(&mut grid, 0, "go htt", true);
+        write_row(&mut grid, 1, "p://z ", false);
+
+        let found = grid.urls();
+        assert_eq!(
+            found,
+            vec![(
+                Pos { row: 0, col: 3 },
+                Pos { row: 1, col: 4 },
+                "http://z".to_string()
+            )]
+        );
+    }
+}
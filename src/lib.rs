@@ -0,0 +1,7 @@
+pub mod attrs;
+pub mod cell;
+pub mod grid;
+pub mod row;
+pub mod screen;
+
+pub use screen::Screen;
@@ -0,0 +1,182 @@
+use crate::attrs::{Attrs, Color};
+use crate::cell::Cell;
+
+#[derive(Clone, Debug)]
+pub struct Row {
+    cells: Vec<Cell>,
+    wrapped: bool,
+}
+
+impl Row {
+    pub fn new(cols: u16) -> Self {
+        Self {
+            cells: vec![Cell::default(); cols as usize],
+            wrapped: false,
+        }
+    }
+
+    pub fn get(&self, col: u16) -> Option<&Cell> {
+        self.cells.get(col as usize)
+    }
+
+    pub fn get_mut(&mut self, col: u16) -> Option<&mut Cell> {
+        self.cells.get_mut(col as usize)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &Cell> {
+        self.cells.iter()
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut Cell> {
+        self.cells.iter_mut()
+    }
+
+    pub fn wrap(&mut self, wrapped: bool) {
+        self.wrapped = wrapped;
+    }
+
+    pub fn wrapped(&self) -> bool {
+        self.wrapped
+    }
+
+    pub fn clear(&mut self, bgcolor: Color) {
+        for cell in &mut self.cells {
+            cell.clear(bgcolor);
+        }
+        self.wrapped = false;
+    }
+
+    pub fn insert(&mut self, i: usize, cell: Cell) {
+        self.cells.insert(i, cell);
+    }
+
+    pub fn remove(&mut self, i: usize) -> Cell {
+        self.cells.remove(i)
+    }
+
+    pub fn resize(&mut self, len: usize, cell: Cell) {
+        self.cells.resize(len, cell);
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.cells.truncate(len);
+    }
+
+    // a wide_spacer cell contributes nothing to the rendered text - it's
+    // the blank left in the last column by Grid::col_wrap when a wide
+    // glyph got pushed to the next row, and the glyph itself is emitted
+    // from that next row.
+    pub fn contents(&self, start: u16, end: u16) -> String {
+        let mut contents = String::new();
+        for cell in self.range(start, end) {
+            if cell.is_wide_spacer() {
+                continue;
+            }
+            if cell.has_contents() {
+                contents.push_str(cell.contents());
+            } else {
+                contents.push(' ');
+            }
+        }
+        contents
+    }
+
+    pub fn contents_formatted(
+        &self,
+        start: u16,
+        end: u16,
+        mut attrs: Attrs,
+    ) -> (Vec<u8>, Attrs, u16) {
+        let mut contents = Vec::new();
+        let mut col = start;
+        for cell in self.range(start, end) {
+            if cell.is_wide_spacer() {
+                col += 1;
+                continue;
+            }
+            if *cell.attrs() != attrs {
+                attrs = *cell.attrs();
+                contents.extend(sgr(&attrs));
+            }
+            if cell.has_contents() {
+                contents.extend(cell.contents().as_bytes());
+            } else {
+                contents.push(b' ');
+            }
+            col += 1;
+        }
+        (contents, attrs, col)
+    }
+
+    pub fn contents_diff(
+        &self,
+        prev: &Self,
+        start: u16,
+        end: u16,
+        mut attrs: Attrs,
+    ) -> (Vec<u8>, Attrs, u16) {
+        let mut contents = Vec::new();
+        let mut col = start;
+        let mut positioned = true;
+        for (cell, prev_cell) in
+            self.range(start, end).zip(prev.range(start, end))
+        {
+            if cell.is_wide_spacer() {
+                col += 1;
+                continue;
+            }
+            if cell == prev_cell {
+                positioned = false;
+                col += 1;
+                continue;
+            }
+            if !positioned {
+                contents.extend(format!("\x1b[{}G", col + 1).into_bytes());
+                positioned = true;
+            }
+            if *cell.attrs() != attrs {
+                attrs = *cell.attrs();
+                contents.extend(sgr(&attrs));
+            }
+            if cell.has_contents() {
+                contents.extend(cell.contents().as_bytes());
+            } else {
+                contents.push(b' ');
+            }
+            col += 1;
+        }
+        (contents, attrs, col)
+    }
+
+    fn range(&self, start: u16, end: u16) -> impl Iterator<Item = &Cell> {
+        let end = end.min(self.cells.len() as u16);
+        self.cells[start as usize..end as usize].iter()
+    }
+}
+
+fn sgr(attrs: &Attrs) -> Vec<u8> {
+    let mut codes = vec!["0".to_string()];
+    if attrs.bold {
+        codes.push("1".to_string());
+    }
+    if attrs.italic {
+        codes.push("3".to_string());
+    }
+    if attrs.underline {
+        codes.push("4".to_string());
+    }
+    if attrs.inverse {
+        codes.push("7".to_string());
+    }
+    match attrs.fgcolor {
+        Color::Default => {}
+        Color::Idx(i) => codes.push(format!("38;5;{i}")),
+        Color::Rgb(r, g, b) => codes.push(format!("38;2;{r};{g};{b}")),
+    }
+    match attrs.bgcolor {
+        Color::Default => {}
+        Color::Idx(i) => codes.push(format!("48;5;{i}")),
+        Color::Rgb(r, g, b) => codes.push(format!("48;2;{r};{g};{b}")),
+    }
+    format!("\x1b[{}m", codes.join(";")).into_bytes()
+}
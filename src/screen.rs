@@ -0,0 +1,20 @@
+#[derive(Clone, Debug)]
+pub struct Screen {
+    grid: crate::grid::Grid,
+}
+
+impl Screen {
+    pub fn new(size: crate::grid::Size, scrollback_len: usize) -> Self {
+        Self {
+            grid: crate::grid::Grid::new(size, scrollback_len),
+        }
+    }
+
+    pub fn grid(&self) -> &crate::grid::Grid {
+        &self.grid
+    }
+
+    pub fn grid_mut(&mut self) -> &mut crate::grid::Grid {
+        &mut self.grid
+    }
+}